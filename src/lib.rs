@@ -11,46 +11,199 @@ impl fmt::Display for OutOfBound {
     }
 }
 
+/// Which way a [`Cursor`] extends a keyset page from its boundary.
+#[derive(Clone, Debug, Eq, PartialEq, Copy)]
+pub enum Direction {
+    Forward,
+    Backward,
+}
+
+/// An opaque position in a keyset-paginated sequence, used to seek the next
+/// or previous window without recomputing an offset from page 0.
+///
+/// A `Cursor` is obtained from [`Page::cursor`] and fed back into
+/// [`Pages::after`] or [`Pages::before`] to keep walking in the same
+/// direction.
+#[derive(Clone, Debug, Eq, PartialEq, Copy)]
+pub struct Cursor {
+    position: usize,
+    direction: Direction,
+}
+
+impl Cursor {
+    pub fn new(position: usize, direction: Direction) -> Cursor {
+        Cursor { position, direction }
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+}
+
 // page_number (offset) is 0-indexed
 
 #[derive(Clone, Debug, Eq, PartialEq, Copy)]
-pub struct Pages {
+pub struct Pages<'a, T = ()> {
     page_number: usize,
+    // Number of pages already yielded from the back by `next_back`, so
+    // `next` and `next_back` can meet in the middle without overlapping.
+    back_offset: usize,
     length: usize,
     per_page: usize,
     html_function: fn(usize, usize) -> String,
+    path_pattern: Option<&'a str>,
+    data: Option<&'a [T]>,
 }
 
-impl Pages {
-    pub fn new(length: usize, per_page: usize, f: Option<fn(usize, usize) -> String>) -> Pages {
+impl<'a> Pages<'a, ()> {
+    pub fn new(
+        length: usize,
+        per_page: usize,
+        f: Option<fn(usize, usize) -> String>,
+    ) -> Pages<'a, ()> {
         Pages {
             page_number: 0,
+            back_offset: 0,
             length,
             per_page,
             html_function: f.unwrap_or(|_, _| -> String { "".to_string() }),
+            path_pattern: None,
+            data: None,
+        }
+    }
+}
+
+impl<'a, T> Pages<'a, T> {
+    /// Paginate over a slice, so that each produced `Page` also carries the
+    /// slice of `data` that falls within its bounds.
+    pub fn over(data: &'a [T], per_page: usize) -> Pages<'a, T> {
+        Pages {
+            page_number: 0,
+            back_offset: 0,
+            length: data.len(),
+            per_page,
+            html_function: |_, _| -> String { "".to_string() },
+            path_pattern: None,
+            data: Some(data),
         }
     }
 
-    pub fn to_page_number(&self, page_number: usize) -> Result<Page, OutOfBound> {
-        let mut page = Page::default();
+    /// Generate each page's `path`/`permalink()` from `pattern`, substituting
+    /// the 1-indexed page number for the literal `{page}` placeholder (e.g.
+    /// `"blog/page/{page}/"`). By convention, page 1 maps to `pattern` cut
+    /// before the path segment that precedes `{page}` (here `"blog/"`),
+    /// rather than `page/1/`.
+    pub fn with_path_pattern(mut self, pattern: &'a str) -> Pages<'a, T> {
+        self.path_pattern = Some(pattern);
+        self
+    }
 
+    pub fn to_page_number(&self, page_number: usize) -> Result<Page<'a, T>, OutOfBound> {
         if page_number >= self.page_count() {
             return Err(OutOfBound);
         }
-        page.page_number = page_number;
-        page.begin = min(page.page_number * self.per_page, self.length);
-        page.end = min(page.begin + self.per_page, self.length);
-        page.length = max(page.end - page.begin, 0);
-
-        if page.length == 0 {
-            page.begin = 0;
-            page.end = 0;
+
+        let begin = min(page_number * self.per_page, self.length);
+        let end = min(begin + self.per_page, self.length);
+        let length = max(end - begin, 0);
+
+        Ok(self.build_page(page_number, begin, length, None))
+    }
+
+    /// The window of up to `per_page` items strictly after `cursor`'s position.
+    ///
+    /// Unlike [`Pages::to_page_number`], this never multiplies by an offset:
+    /// it walks forward from `cursor` directly, so it stays cheap even when
+    /// the backing store can't skip rows efficiently. The returned page
+    /// exposes a forward [`Cursor`] for its last element, so repeatedly
+    /// calling `after` with the previous result's cursor walks the rest of
+    /// the sequence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cursor`'s direction is [`Direction::Backward`] — such a
+    /// cursor was produced by [`Pages::before`] and is only meaningful
+    /// passed back into `before`.
+    pub fn after(&self, cursor: &Cursor) -> Page<'a, T> {
+        assert_eq!(
+            cursor.direction(),
+            Direction::Forward,
+            "Pages::after requires a forward cursor, got a backward one from Pages::before"
+        );
+        let begin = min(cursor.position().saturating_add(1), self.length);
+        let length = min(self.per_page, self.length - begin);
+        let next_cursor = if length == 0 {
+            None
+        } else {
+            Some(Cursor::new(begin + length - 1, Direction::Forward))
+        };
+        self.build_page(0, begin, length, next_cursor)
+    }
+
+    /// The window of up to `per_page` items strictly before `cursor`'s position.
+    ///
+    /// The returned page exposes a backward [`Cursor`] for its first element,
+    /// so repeatedly calling `before` with the previous result's cursor walks
+    /// back through the whole sequence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cursor`'s direction is [`Direction::Forward`] — such a
+    /// cursor was produced by [`Pages::after`] and is only meaningful passed
+    /// back into `after`.
+    pub fn before(&self, cursor: &Cursor) -> Page<'a, T> {
+        assert_eq!(
+            cursor.direction(),
+            Direction::Backward,
+            "Pages::before requires a backward cursor, got a forward one from Pages::after"
+        );
+        let end = min(cursor.position(), self.length);
+        let length = min(self.per_page, end);
+        let begin = end - length;
+        let next_cursor = if length == 0 {
+            None
+        } else {
+            Some(Cursor::new(begin, Direction::Backward))
+        };
+        self.build_page(0, begin, length, next_cursor)
+    }
+
+    fn build_page(
+        &self,
+        page_number: usize,
+        begin: usize,
+        length: usize,
+        cursor: Option<Cursor>,
+    ) -> Page<'a, T> {
+        let (begin, mut end) = if length == 0 {
+            (0, 0)
+        } else {
+            (begin, begin + length)
         };
-        if page.length > 0 {
-            page.end -= 1;
+        let items = self.data.map(|data| &data[begin..begin + length]);
+        let html = (self.html_function)(begin, length);
+        let path = match self.path_pattern {
+            Some(pattern) => render_path(pattern, page_number),
+            None => String::new(),
         };
-        page.html = (self.html_function)(page.begin, page.length);
-        Ok(page)
+        if length > 0 {
+            end -= 1;
+        };
+
+        Page {
+            page_number,
+            length,
+            begin,
+            end,
+            html,
+            items,
+            cursor,
+            path,
+        }
     }
 
     pub fn offset(&self) -> usize {
@@ -68,53 +221,178 @@ impl Pages {
     pub fn page_count(&self) -> usize {
         (self.length + self.per_page - 1) / self.per_page
     }
+
+    /// The first page, or `None` if there are no pages.
+    pub fn first(&self) -> Option<Page<'a, T>> {
+        if self.page_count() == 0 {
+            return None;
+        }
+        self.to_page_number(0).ok()
+    }
+
+    /// The last page, or `None` if there are no pages.
+    ///
+    /// Named `last_page` rather than `last` because `Pages` also implements
+    /// [`Iterator`], whose by-value `Iterator::last` would
+    /// otherwise shadow this cheap, non-consuming lookup.
+    pub fn last_page(&self) -> Option<Page<'a, T>> {
+        let page_count = self.page_count();
+        if page_count == 0 {
+            return None;
+        }
+        self.to_page_number(page_count - 1).ok()
+    }
+
+    /// The page before `page_number`, or `None` if `page_number` is the first page.
+    pub fn previous_page(&self, page_number: usize) -> Option<Page<'a, T>> {
+        if page_number == 0 {
+            return None;
+        }
+        self.to_page_number(page_number - 1).ok()
+    }
+
+    /// The page after `page_number`, or `None` if `page_number` is the last page.
+    pub fn next_page(&self, page_number: usize) -> Option<Page<'a, T>> {
+        self.to_page_number(page_number + 1).ok()
+    }
+
+    /// The page numbers to display around `current`, for rendering a windowed
+    /// paginator (e.g. `« First  ‹ Prev  3 4 [5] 6 7  Next ›  Last »`).
+    ///
+    /// `current` is clamped to a valid page number first. The returned pages
+    /// are `current - radius ..= current + radius`, intersected with the
+    /// valid page range. The first and last elements of the result are the
+    /// window's edges: if the first is greater than `0`, callers should
+    /// render a leading ellipsis; if the last is less than `page_count() - 1`,
+    /// a trailing one.
+    pub fn window(&self, current: usize, radius: usize) -> Vec<usize> {
+        let page_count = self.page_count();
+        if page_count == 0 {
+            return Vec::new();
+        }
+        let current = min(current, page_count - 1);
+        let low = current.saturating_sub(radius);
+        let high = min(current + radius, page_count - 1);
+        (low..=high).collect()
+    }
 }
 
-impl Iterator for Pages {
-    type Item = Page;
+impl<'a, T> Iterator for Pages<'a, T> {
+    type Item = Page<'a, T>;
+
     fn next(&mut self) -> Option<Self::Item> {
-        let page: Option<Page> = match self.to_page_number(self.page_number) {
-            Ok(page) => Some(page),
-            Err(_msg) => None,
-        };
+        if self.page_number + self.back_offset >= self.page_count() {
+            return None;
+        }
+        let page = self.to_page_number(self.page_number).ok();
         self.page_number += 1;
         page
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.page_number = self.page_number.saturating_add(n);
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len();
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Pages<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.page_number + self.back_offset >= self.page_count() {
+            return None;
+        }
+        let page_number = self.page_count() - self.back_offset - 1;
+        let page = self.to_page_number(page_number).ok();
+        self.back_offset += 1;
+        page
+    }
 }
 
-impl IntoIterator for &Pages {
-    type Item = Page;
-    type IntoIter = Pages;
+impl<'a, T> ExactSizeIterator for Pages<'a, T> {
+    fn len(&self) -> usize {
+        self.page_count()
+            .saturating_sub(self.page_number + self.back_offset)
+    }
+}
+
+impl<'a, T> IntoIterator for &Pages<'a, T> {
+    type Item = Page<'a, T>;
+    type IntoIter = Pages<'a, T>;
 
-    fn into_iter(self) -> Pages {
+    fn into_iter(self) -> Pages<'a, T> {
         Pages {
             page_number: 0,
+            back_offset: 0,
             length: self.length(),
             per_page: self.per_page(),
             html_function: self.html_function,
+            path_pattern: self.path_pattern,
+            data: self.data,
         }
     }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
-pub struct Page {
+pub struct Page<'a, T = ()> {
     pub page_number: usize,
     pub length: usize,
     pub begin: usize,
     pub end: usize,
     pub html: String,
+    pub items: Option<&'a [T]>,
+    /// A cursor for this page's boundary element, set only when the page
+    /// came from [`Pages::after`] or [`Pages::before`]. Feed it back into the
+    /// same method to keep seeking in that direction.
+    pub cursor: Option<Cursor>,
+    /// This page's path, generated from the pattern passed to
+    /// [`Pages::with_path_pattern`]. Empty if no pattern was set.
+    pub path: String,
 }
 
-impl Page {
+impl<'a, T> Page<'a, T> {
     pub fn is_empty(&self) -> bool {
         self.length == 0
     }
+
+    /// `base_url` joined with this page's [`Page::path`].
+    pub fn permalink(&self, base_url: &str) -> String {
+        if self.path.is_empty() {
+            return base_url.to_string();
+        }
+        format!(
+            "{}/{}",
+            base_url.trim_end_matches('/'),
+            self.path.trim_start_matches('/')
+        )
+    }
+}
+
+/// Substitutes the 1-indexed `page_number` into `pattern`'s `{page}`
+/// placeholder. By convention, page 1 maps to `pattern` cut before the path
+/// segment that precedes `{page}` (e.g. `"blog/page/{page}/"` becomes
+/// `"blog/"` and `"page/{page}/"` becomes `""`), rather than `page/1/`.
+fn render_path(pattern: &str, page_number: usize) -> String {
+    let page = page_number + 1;
+    if page == 1 {
+        if let Some(page_idx) = pattern.find("{page}") {
+            let prefix = &pattern[..page_idx];
+            return match prefix.trim_end_matches('/').rfind('/') {
+                Some(slash_idx) => prefix[..=slash_idx].to_string(),
+                None => String::new(),
+            };
+        }
+    }
+    pattern.replace("{page}", &page.to_string())
 }
 
 #[cfg(test)]
 mod tests {
 
-    use super::{Page, Pages};
+    use super::{Cursor, Direction, Page, Pages};
 
     fn get_url() -> String {
         "www.test.com/".to_string()
@@ -132,6 +410,9 @@ mod tests {
                 begin: 0,
                 end: 1,
                 html: "".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             })
         );
         assert_eq!(
@@ -142,6 +423,9 @@ mod tests {
                 begin: 2,
                 end: 3,
                 html: "".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             })
         );
         assert_eq!(
@@ -152,6 +436,9 @@ mod tests {
                 begin: 4,
                 end: 5,
                 html: "".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             })
         );
         assert_eq!(pages_iter.next(), None);
@@ -159,7 +446,7 @@ mod tests {
 
     #[test]
     fn default_page() {
-        let page = Page::default();
+        let page: Page<()> = Page::default();
         assert_eq!(
             page,
             Page {
@@ -167,7 +454,10 @@ mod tests {
                 length: 0,
                 begin: 0,
                 end: 0,
-                html: "".to_string()
+                html: "".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             }
         );
     }
@@ -191,7 +481,10 @@ mod tests {
                 length: 5,
                 begin: 0,
                 end: 4,
-                html: "".to_string()
+                html: "".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             }
         );
         assert_eq!(
@@ -207,7 +500,10 @@ mod tests {
                 length: 5,
                 begin: 5,
                 end: 9,
-                html: "".to_string()
+                html: "".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             }
         );
     }
@@ -231,7 +527,10 @@ mod tests {
                 length: 0,
                 begin: 0,
                 end: 0,
-                html: "".to_string()
+                html: "".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             }
         );
     }
@@ -267,7 +566,10 @@ mod tests {
                 length: 0,
                 begin: 0,
                 end: 0,
-                html: "".to_string()
+                html: "".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             }
         );
     }
@@ -302,8 +604,10 @@ mod tests {
                 length: 5,
                 begin: 0,
                 end: 4,
-                html: "<a href=\"www.test.com/0\"></a></br><a href=\"www.test.com/1\"></a></br><a href=\"www.test.com/2\"></a></br><a href=\"www.test.com/3\"></a></br><a href=\"www.test.com/4\"></a></br>"
-                    .to_string()
+                html: "<a href=\"www.test.com/0\"></a></br><a href=\"www.test.com/1\"></a></br><a href=\"www.test.com/2\"></a></br><a href=\"www.test.com/3\"></a></br><a href=\"www.test.com/4\"></a></br>".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             }
         );
         assert_eq!(
@@ -319,7 +623,10 @@ mod tests {
                 length: 0,
                 begin: 0,
                 end: 0,
-                html: "".to_string()
+                html: "".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             }
         );
     }
@@ -355,7 +662,10 @@ mod tests {
                 length: 1,
                 begin: 0,
                 end: 0,
-                html: "<a href=\"www.test.com/0\"></a></br>".to_string()
+                html: "<a href=\"www.test.com/0\"></a></br>".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             }
         );
         assert_eq!(
@@ -371,7 +681,10 @@ mod tests {
                 length: 0,
                 begin: 0,
                 end: 0,
-                html: "".to_string()
+                html: "".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             }
         );
     }
@@ -406,8 +719,10 @@ mod tests {
                 length: 2,
                 begin: 0,
                 end: 1,
-                html: "<a href=\"www.test.com/0\"></a></br><a href=\"www.test.com/1\"></a></br>"
-                    .to_string()
+                html: "<a href=\"www.test.com/0\"></a></br><a href=\"www.test.com/1\"></a></br>".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             }
         );
         assert_eq!(
@@ -423,8 +738,10 @@ mod tests {
                 length: 2,
                 begin: 2,
                 end: 3,
-                html: "<a href=\"www.test.com/2\"></a></br><a href=\"www.test.com/3\"></a></br>"
-                    .to_string()
+                html: "<a href=\"www.test.com/2\"></a></br><a href=\"www.test.com/3\"></a></br>".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             }
         );
         assert_eq!(
@@ -440,7 +757,10 @@ mod tests {
                 length: 1,
                 begin: 4,
                 end: 4,
-                html: "<a href=\"www.test.com/4\"></a></br>".to_string()
+                html: "<a href=\"www.test.com/4\"></a></br>".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             }
         );
         assert_eq!(
@@ -456,7 +776,10 @@ mod tests {
                 length: 0,
                 begin: 0,
                 end: 0,
-                html: "".to_string()
+                html: "".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             }
         );
     }
@@ -493,8 +816,10 @@ mod tests {
                 length: 2,
                 begin: 0,
                 end: 1,
-                html: "<a href=\"www.test.com/0\"></a></br><a href=\"www.test.com/1\"></a></br>"
-                    .to_string()
+                html: "<a href=\"www.test.com/0\"></a></br><a href=\"www.test.com/1\"></a></br>".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             }
         );
         assert_eq!(
@@ -510,8 +835,10 @@ mod tests {
                 length: 2,
                 begin: 2,
                 end: 3,
-                html: "<a href=\"www.test.com/2\"></a></br><a href=\"www.test.com/3\"></a></br>"
-                    .to_string()
+                html: "<a href=\"www.test.com/2\"></a></br><a href=\"www.test.com/3\"></a></br>".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             }
         );
         assert_eq!(
@@ -527,8 +854,10 @@ mod tests {
                 length: 2,
                 begin: 4,
                 end: 5,
-                html: "<a href=\"www.test.com/4\"></a></br><a href=\"www.test.com/5\"></a></br>"
-                    .to_string()
+                html: "<a href=\"www.test.com/4\"></a></br><a href=\"www.test.com/5\"></a></br>".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             }
         );
         assert_eq!(
@@ -544,7 +873,10 @@ mod tests {
                 length: 0,
                 begin: 0,
                 end: 0,
-                html: "".to_string()
+                html: "".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             }
         );
     }
@@ -579,8 +911,10 @@ mod tests {
                 length: 3,
                 begin: 0,
                 end: 2,
-                html: "<a href=\"www.test.com/0\"></a></br><a href=\"www.test.com/1\"></a></br><a href=\"www.test.com/2\"></a></br>"
-                    .to_string()
+                html: "<a href=\"www.test.com/0\"></a></br><a href=\"www.test.com/1\"></a></br><a href=\"www.test.com/2\"></a></br>".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             }
         );
         assert_eq!(
@@ -596,8 +930,10 @@ mod tests {
                 length: 2,
                 begin: 3,
                 end: 4,
-                html: "<a href=\"www.test.com/3\"></a></br><a href=\"www.test.com/4\"></a></br>"
-                    .to_string()
+                html: "<a href=\"www.test.com/3\"></a></br><a href=\"www.test.com/4\"></a></br>".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             }
         );
         assert_eq!(
@@ -613,7 +949,10 @@ mod tests {
                 length: 0,
                 begin: 0,
                 end: 0,
-                html: "".to_string()
+                html: "".to_string(),
+                items: None,
+                cursor: None,
+                path: String::new(),
             }
         );
     }
@@ -643,7 +982,10 @@ mod tests {
                     length: 1,
                     begin: 0,
                     end: 0,
-                    html: "<a href=\"www.test.com/0\"></a></br>".to_string()
+                    html: "<a href=\"www.test.com/0\"></a></br>".to_string(),
+                    items: None,
+                    cursor: None,
+                    path: String::new(),
                 }
             );
         }
@@ -674,7 +1016,10 @@ mod tests {
                     length: 1,
                     begin: 0,
                     end: 0,
-                    html: "<a href=\"www.test.com/0\"></a></br>".to_string()
+                    html: "<a href=\"www.test.com/0\"></a></br>".to_string(),
+                    items: None,
+                    cursor: None,
+                    path: String::new(),
                 }
             );
         }
@@ -682,10 +1027,10 @@ mod tests {
 
     #[test]
     fn is_empty() {
-        let empty_page = Page::default();
+        let empty_page: Page<()> = Page::default();
         assert!(empty_page.is_empty());
 
-        let filled_page = Page {
+        let filled_page: Page<()> = Page {
             length: 1,
             ..Page::default()
         };
@@ -721,4 +1066,189 @@ mod tests {
         let pages = Pages::new(99, 5, None);
         assert_eq!(20, pages.page_count());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn first_and_last() {
+        let pages = Pages::new(100, 5, None);
+        assert_eq!(pages.first(), pages.to_page_number(0).ok());
+        assert_eq!(pages.last_page(), pages.to_page_number(19).ok());
+
+        let empty = Pages::new(0, 5, None);
+        assert_eq!(empty.first(), None);
+        assert_eq!(empty.last_page(), None);
+    }
+
+    #[test]
+    fn previous_and_next() {
+        let pages = Pages::new(100, 5, None);
+        assert_eq!(pages.previous_page(0), None);
+        assert_eq!(pages.previous_page(5), pages.to_page_number(4).ok());
+        assert_eq!(pages.next_page(5), pages.to_page_number(6).ok());
+        assert_eq!(pages.next_page(19), None);
+    }
+
+    #[test]
+    fn window_centered() {
+        let pages = Pages::new(100, 5, None);
+        assert_eq!(pages.window(10, 2), vec![8, 9, 10, 11, 12]);
+    }
+
+    #[test]
+    fn window_clamped_to_edges() {
+        let pages = Pages::new(100, 5, None);
+        assert_eq!(pages.window(0, 2), vec![0, 1, 2]);
+        assert_eq!(pages.window(19, 2), vec![17, 18, 19]);
+        assert_eq!(pages.window(100, 2), vec![17, 18, 19]);
+    }
+
+    #[test]
+    fn window_on_empty_pages() {
+        let pages = Pages::new(0, 5, None);
+        assert_eq!(pages.window(0, 2), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn over_slices_the_data() {
+        let data = vec!["a", "b", "c", "d", "e"];
+        let pages = Pages::over(&data, 2);
+
+        assert_eq!(pages.length(), 5);
+        assert_eq!(pages.page_count(), 3);
+
+        let page = pages.to_page_number(0).unwrap();
+        assert_eq!(page.items, Some(&["a", "b"][..]));
+
+        let page = pages.to_page_number(2).unwrap();
+        assert_eq!(page.items, Some(&["e"][..]));
+    }
+
+    #[test]
+    fn over_iterates_pages_with_items() {
+        let data = vec![1, 2, 3, 4, 5];
+        let pages = Pages::over(&data, 2);
+        let items: Vec<i32> = (&pages)
+            .into_iter()
+            .flat_map(|page| page.items.unwrap().to_vec())
+            .collect();
+        assert_eq!(items, data);
+    }
+
+    #[test]
+    fn after_walks_forward_from_a_cursor() {
+        let data = vec![1, 2, 3, 4, 5];
+        let pages = Pages::over(&data, 2);
+
+        let start = Cursor::new(0, Direction::Forward);
+        let page = pages.after(&start);
+        assert_eq!(page.items, Some(&[2, 3][..]));
+        let cursor = page.cursor.unwrap();
+        assert_eq!(cursor.position(), 2);
+        assert_eq!(cursor.direction(), Direction::Forward);
+
+        let page = pages.after(&cursor);
+        assert_eq!(page.items, Some(&[4, 5][..]));
+
+        let page = pages.after(&page.cursor.unwrap());
+        assert!(page.is_empty());
+        assert_eq!(page.cursor, None);
+    }
+
+    #[test]
+    fn before_walks_backward_from_a_cursor() {
+        let data = vec![1, 2, 3, 4, 5];
+        let pages = Pages::over(&data, 2);
+
+        let end = Cursor::new(5, Direction::Backward);
+        let page = pages.before(&end);
+        assert_eq!(page.items, Some(&[4, 5][..]));
+        let cursor = page.cursor.unwrap();
+        assert_eq!(cursor.position(), 3);
+        assert_eq!(cursor.direction(), Direction::Backward);
+
+        let page = pages.before(&cursor);
+        assert_eq!(page.items, Some(&[2, 3][..]));
+
+        let page = pages.before(&page.cursor.unwrap());
+        assert_eq!(page.items, Some(&[1][..]));
+
+        let page = pages.before(&page.cursor.unwrap());
+        assert!(page.is_empty());
+        assert_eq!(page.cursor, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Pages::after requires a forward cursor")]
+    fn after_rejects_a_backward_cursor() {
+        let data = vec![1, 2, 3, 4, 5];
+        let pages = Pages::over(&data, 2);
+        pages.after(&Cursor::new(0, Direction::Backward));
+    }
+
+    #[test]
+    #[should_panic(expected = "Pages::before requires a backward cursor")]
+    fn before_rejects_a_forward_cursor() {
+        let data = vec![1, 2, 3, 4, 5];
+        let pages = Pages::over(&data, 2);
+        pages.before(&Cursor::new(5, Direction::Forward));
+    }
+
+    #[test]
+    fn exact_size() {
+        let pages = Pages::new(10, 2, None);
+        let mut pages_iter = pages.into_iter();
+        assert_eq!(pages_iter.len(), 5);
+        pages_iter.next();
+        assert_eq!(pages_iter.len(), 4);
+    }
+
+    #[test]
+    fn nth_jumps_directly() {
+        let pages = Pages::new(10, 2, None);
+        let mut pages_iter = pages.into_iter();
+        assert_eq!(pages_iter.nth(3), pages.to_page_number(3).ok());
+        assert_eq!(pages_iter.next(), pages.to_page_number(4).ok());
+    }
+
+    #[test]
+    fn rev_walks_pages_backwards() {
+        let pages = Pages::new(10, 2, None);
+        let page_numbers: Vec<usize> = pages.rev().map(|page| page.page_number).collect();
+        assert_eq!(page_numbers, vec![4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn next_and_next_back_meet_in_the_middle() {
+        let pages = Pages::new(10, 2, None);
+        let mut pages_iter = pages.into_iter();
+        assert_eq!(pages_iter.next(), pages.to_page_number(0).ok());
+        assert_eq!(pages_iter.next_back(), pages.to_page_number(4).ok());
+        assert_eq!(pages_iter.next(), pages.to_page_number(1).ok());
+        assert_eq!(pages_iter.next_back(), pages.to_page_number(3).ok());
+        assert_eq!(pages_iter.next(), pages.to_page_number(2).ok());
+        assert_eq!(pages_iter.next(), None);
+        assert_eq!(pages_iter.next_back(), None);
+    }
+
+    #[test]
+    fn default_path_is_empty() {
+        let pages = Pages::new(6, 2, None);
+        let page = pages.to_page_number(1).unwrap();
+        assert_eq!(page.path, "");
+        assert_eq!(page.permalink(&get_url()), get_url());
+    }
+
+    #[test]
+    fn with_path_pattern_substitutes_the_page_number() {
+        let pages = Pages::new(6, 2, None).with_path_pattern("blog/page/{page}/");
+        let page = pages.to_page_number(2).unwrap();
+        assert_eq!(page.path, "blog/page/3/");
+        assert_eq!(page.permalink(&get_url()), "www.test.com/blog/page/3/");
+    }
+
+    #[test]
+    fn with_path_pattern_strips_the_page_segment_for_page_one() {
+        let pages = Pages::new(6, 2, None).with_path_pattern("blog/page/{page}/");
+        let page = pages.to_page_number(0).unwrap();
+        assert_eq!(page.path, "blog/");
+    }
+}